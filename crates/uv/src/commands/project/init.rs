@@ -1,8 +1,9 @@
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 
+use clap::ValueEnum;
 use owo_colors::OwoColorize;
 use pep508_rs::PackageName;
 use uv_cache::Cache;
@@ -19,6 +20,442 @@ use uv_warnings::warn_user_once;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
+/// The build backend to use when generating a `[build-system]` table for a new project.
+///
+/// Selecting [`ProjectBuildBackend::Maturin`] produces a mixed Rust/Python project instead of a
+/// pure-Python one, since `maturin` builds extension modules from a Rust source tree.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum ProjectBuildBackend {
+    /// Use [hatchling](https://pypi.org/project/hatchling/) as the build backend.
+    #[default]
+    Hatchling,
+    /// Use [setuptools](https://pypi.org/project/setuptools/) as the build backend.
+    Setuptools,
+    /// Use [flit-core](https://pypi.org/project/flit-core/) as the build backend.
+    Flit,
+    /// Use [maturin](https://pypi.org/project/maturin/) as the build backend.
+    Maturin,
+}
+
+impl ProjectBuildBackend {
+    /// The `requires` entry for the `[build-system]` table.
+    fn requires(self) -> &'static str {
+        match self {
+            Self::Hatchling => r#"["hatchling"]"#,
+            Self::Setuptools => r#"["setuptools>=70.0"]"#,
+            Self::Flit => r#"["flit-core>=3.9,<4"]"#,
+            Self::Maturin => r#"["maturin>=1.5,<2.0"]"#,
+        }
+    }
+
+    /// The `build-backend` entry for the `[build-system]` table.
+    fn module(self) -> &'static str {
+        match self {
+            Self::Hatchling => "hatchling.build",
+            Self::Setuptools => "setuptools.build_meta",
+            Self::Flit => "flit_core.buildapi",
+            Self::Maturin => "maturin",
+        }
+    }
+
+    /// Whether this backend builds a Rust extension module via `pyo3`/`maturin`, rather than a
+    /// pure-Python package.
+    fn is_maturin(self) -> bool {
+        matches!(self, Self::Maturin)
+    }
+}
+
+/// The on-disk layout to use for a new project's Python code.
+///
+/// This mirrors the resolved, internal [`ProjectLayout`], but is flattened to a plain enum so it
+/// can be parsed directly from `--layout`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum ProjectLayoutArg {
+    /// Place `{name}/__init__.py` directly in the project root.
+    Flat,
+    /// Place `{name}/__init__.py` under `src/`.
+    #[default]
+    Src,
+    /// A mixed Rust/Python layout, as used by `maturin`.
+    Mixed,
+}
+
+/// The resolved on-disk layout for a new project's Python code.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ProjectLayout {
+    /// `{name}/__init__.py` lives directly in the project root.
+    Flat,
+    /// `src/{name}/__init__.py`.
+    Src,
+    /// A mixed Rust/Python layout, as used by `maturin`: `python/{name}/__init__.py` alongside a
+    /// `Cargo.toml` and `src/lib.rs`.
+    Mixed,
+}
+
+impl ProjectLayout {
+    /// Resolve the layout to use from the explicit `--layout`/`--flat` flags, the selected build
+    /// backend, and (failing those) the layout already present on disk.
+    fn resolve(
+        layout: Option<ProjectLayoutArg>,
+        flat: bool,
+        build_backend: Option<ProjectBuildBackend>,
+        project_dir: &Path,
+        crate_name: &str,
+    ) -> Self {
+        if flat {
+            return Self::Flat;
+        }
+
+        if let Some(layout) = layout {
+            return match layout {
+                ProjectLayoutArg::Flat => Self::Flat,
+                ProjectLayoutArg::Src => Self::Src,
+                ProjectLayoutArg::Mixed => Self::Mixed,
+            };
+        }
+
+        // A Rust-based build backend implies a mixed layout.
+        if build_backend.is_some_and(ProjectBuildBackend::is_maturin) {
+            return Self::Mixed;
+        }
+
+        // Otherwise, extend whatever layout the directory already has, rather than fighting it.
+        if project_dir.join("Cargo.toml").exists() {
+            // An existing Rust crate: treat this as a mixed project, rather than writing Python
+            // scaffolding into what is actually the Rust source tree (e.g. a bare `src/main.rs`).
+            Self::Mixed
+        } else if is_python_src_layout(project_dir) {
+            Self::Src
+        } else if project_dir.join(crate_name).join("__init__.py").exists() {
+            Self::Flat
+        } else {
+            Self::Src
+        }
+    }
+
+    /// The directory that should contain `__init__.py` for the Python package.
+    ///
+    /// This is keyed on the sanitized `crate_name` (e.g. `my_project`), not the raw distribution
+    /// `name` (e.g. `my-project`), since the package directory also doubles as the Python import
+    /// path and must be a valid Python identifier.
+    fn package_dir(self, project_dir: &Path, crate_name: &str) -> PathBuf {
+        match self {
+            Self::Flat => project_dir.join(crate_name),
+            Self::Src => project_dir.join("src").join(crate_name),
+            Self::Mixed => project_dir.join("python").join(crate_name),
+        }
+    }
+}
+
+/// Whether `project_dir/src` looks like an existing Python `src`-layout package (i.e. it
+/// contains at least one `__init__.py`), as opposed to, e.g., a Rust crate's `src/` directory
+/// full of `.rs` files.
+fn is_python_src_layout(project_dir: &Path) -> bool {
+    let Ok(entries) = fs_err::read_dir(project_dir.join("src")) else {
+        return false;
+    };
+    entries
+        .filter_map(Result::ok)
+        .any(|entry| entry.path().join("__init__.py").is_file())
+}
+
+/// The context shared by every scaffold template rendered during `uv init`.
+///
+/// Derived values, like [`InitContext::crate_name`], are computed once up front so that every
+/// template sees the same values rather than re-deriving them.
+#[derive(Debug, Clone)]
+struct InitContext {
+    /// The distribution name of the project, e.g. `my-project`.
+    name: PackageName,
+    /// `name` with `-` replaced with `_`, for use as a Rust crate name or Python import name.
+    crate_name: String,
+    /// The resolved build backend, if any.
+    build_backend: Option<ProjectBuildBackend>,
+    /// The resolved project layout.
+    layout: ProjectLayout,
+    /// Whether to scaffold a runnable application entry point (`--app`/`--script`).
+    app: bool,
+}
+
+impl InitContext {
+    fn new(
+        name: PackageName,
+        crate_name: String,
+        build_backend: Option<ProjectBuildBackend>,
+        layout: ProjectLayout,
+        app: bool,
+    ) -> Self {
+        Self {
+            name,
+            crate_name,
+            build_backend,
+            layout,
+            app,
+        }
+    }
+
+    /// Build the [`minijinja::Value`] exposed to every template.
+    fn to_minijinja(&self) -> minijinja::Value {
+        minijinja::context! {
+            name => self.name.to_string(),
+            crate_name => self.crate_name,
+            is_maturin => self.build_backend.is_some_and(ProjectBuildBackend::is_maturin),
+            build_system_requires => self.build_backend.map(ProjectBuildBackend::requires),
+            build_system_module => self.build_backend.map(ProjectBuildBackend::module),
+            python_source => matches!(self.layout, ProjectLayout::Mixed),
+            is_app => self.app,
+        }
+    }
+}
+
+/// The built-in scaffold templates, used unless overridden by `--template-dir`.
+const PYPROJECT_TOML_TEMPLATE: &str = include_str!("init/templates/pyproject.toml.jinja");
+const INIT_PY_TEMPLATE: &str = include_str!("init/templates/__init__.py.jinja");
+const CARGO_TOML_TEMPLATE: &str = include_str!("init/templates/Cargo.toml.jinja");
+const LIB_RS_TEMPLATE: &str = include_str!("init/templates/lib.rs.jinja");
+const GITIGNORE_TEMPLATE: &str = include_str!("init/templates/.gitignore.jinja");
+const MAIN_PY_TEMPLATE: &str = include_str!("init/templates/main.py.jinja");
+
+/// Look up a named built-in scaffold template.
+fn builtin_template(name: &str) -> Result<&'static str> {
+    match name {
+        "pyproject.toml.jinja" => Ok(PYPROJECT_TOML_TEMPLATE),
+        "__init__.py.jinja" => Ok(INIT_PY_TEMPLATE),
+        "Cargo.toml.jinja" => Ok(CARGO_TOML_TEMPLATE),
+        "lib.rs.jinja" => Ok(LIB_RS_TEMPLATE),
+        ".gitignore.jinja" => Ok(GITIGNORE_TEMPLATE),
+        "main.py.jinja" => Ok(MAIN_PY_TEMPLATE),
+        _ => anyhow::bail!("Unknown scaffold template: `{name}`"),
+    }
+}
+
+/// Render a named scaffold template, preferring a user override from `template_dir` when one
+/// exists, and falling back to the built-in template otherwise.
+fn render_template(
+    name: &str,
+    template_dir: Option<&Path>,
+    context: &InitContext,
+) -> Result<String> {
+    let source = match template_dir.map(|dir| dir.join(name)) {
+        Some(path) if path.is_file() => fs_err::read_to_string(path)?,
+        _ => builtin_template(name)?.to_string(),
+    };
+
+    let mut env = minijinja::Environment::new();
+    // Match Jinja's classic whitespace handling: a `{% ... %}` tag consumes its own line rather
+    // than leaving a blank line behind, so built-in templates can gate optional sections with
+    // `{% if %}`/`{% endif %}` without producing stray blank lines in the rendered output.
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    // Keep the template's final newline, so rendered files end with a newline.
+    env.set_keep_trailing_newline(true);
+    env.add_template(name, &source)?;
+    Ok(env.get_template(name)?.render(context.to_minijinja())?)
+}
+
+/// Create the `Cargo.toml` and `src/lib.rs` for a `maturin`-backed mixed Rust/Python project.
+///
+/// Either file that already exists (e.g. in an existing Rust crate) is left untouched.
+fn init_maturin_project(
+    project_dir: &Path,
+    template_dir: Option<&Path>,
+    context: &InitContext,
+) -> Result<()> {
+    let cargo_toml = project_dir.join("Cargo.toml");
+    if !cargo_toml.try_exists()? {
+        fs_err::write(
+            cargo_toml,
+            render_template("Cargo.toml.jinja", template_dir, context)?,
+        )?;
+    }
+
+    let rust_src = project_dir.join("src");
+    fs_err::create_dir_all(&rust_src)?;
+    let lib_rs = rust_src.join("lib.rs");
+    if !lib_rs.try_exists()? {
+        fs_err::write(
+            lib_rs,
+            render_template("lib.rs.jinja", template_dir, context)?,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The answers collected from an `--interactive` `uv init` prompt session.
+struct InteractiveAnswers {
+    build_backend: Option<ProjectBuildBackend>,
+    layout: Option<ProjectLayoutArg>,
+    app: bool,
+}
+
+/// Whether to run `uv init` in interactive mode: either the user asked for it explicitly, or
+/// we're attached to a TTY and the key choices (backend, layout) weren't already pinned down by
+/// flags or `--flat`. `--no-interactive` always wins, so scripts and non-TTY callers that happen
+/// to omit `--build-backend`/`--layout` never block on a prompt.
+///
+/// An explicit `--interactive` always triggers a prompt, even alongside `--flat`: `--flat` only
+/// pins the *layout* answer, so the backend and app-vs-library questions are still worth asking.
+fn should_prompt(
+    interactive: bool,
+    no_interactive: bool,
+    build_backend: Option<ProjectBuildBackend>,
+    layout: Option<ProjectLayoutArg>,
+    flat: bool,
+) -> bool {
+    use std::io::IsTerminal;
+
+    if no_interactive {
+        return false;
+    }
+
+    interactive
+        || (std::io::stdin().is_terminal()
+            && std::io::stdout().is_terminal()
+            && build_backend.is_none()
+            && layout.is_none()
+            && !flat)
+}
+
+/// The build backends offered by the interactive backend prompt, paired with their menu labels.
+///
+/// When `flat` is set, the layout is already pinned to `Flat`, and `maturin` requires a mixed
+/// layout, so offering it would only let the user pick a combination that's rejected later by
+/// [`validate_layout_backend`] with a confusing "`--layout flat` was requested" message (they
+/// never typed `--layout` at all). Drop it from the menu instead.
+fn backend_choices(flat: bool) -> Vec<(ProjectBuildBackend, &'static str)> {
+    let mut choices = vec![
+        (ProjectBuildBackend::Hatchling, "hatchling"),
+        (ProjectBuildBackend::Setuptools, "setuptools"),
+        (ProjectBuildBackend::Flit, "flit"),
+    ];
+    if !flat {
+        choices.push((ProjectBuildBackend::Maturin, "maturin (Rust extension)"));
+    }
+    choices
+}
+
+/// Prompt the user to select a binding/build backend, project layout, and app-vs-library mode.
+///
+/// The backend prompt is pre-filled with `--build-backend`, or `hatchling` when none was given,
+/// and always yields a backend; there is no "no backend" choice. The layout prompt is pre-filled
+/// with `--layout`, or `src` when none was given; the layout already on disk is not considered.
+/// The app-vs-library prompt is pre-filled with `--app`.
+///
+/// When `flat` is set, the layout is already pinned by `--flat`, so the layout question is
+/// skipped rather than asking for an answer that would just be overridden.
+fn prompt_interactive(
+    build_backend: Option<ProjectBuildBackend>,
+    layout: Option<ProjectLayoutArg>,
+    app: bool,
+    flat: bool,
+) -> Result<InteractiveAnswers> {
+    let (backends, backend_labels): (Vec<_>, Vec<_>) = backend_choices(flat).into_iter().unzip();
+    let backend_default = backends
+        .iter()
+        .position(|backend| Some(*backend) == build_backend)
+        .unwrap_or(0);
+    let backend_index = dialoguer::Select::new()
+        .with_prompt("Select a build backend")
+        .items(&backend_labels)
+        .default(backend_default)
+        .interact()?;
+    let build_backend = Some(backends[backend_index]);
+
+    let layout = if flat {
+        layout
+    } else {
+        let layouts = [
+            ProjectLayoutArg::Src,
+            ProjectLayoutArg::Flat,
+            ProjectLayoutArg::Mixed,
+        ];
+        let layout_labels = [
+            "src (`src/{name}/__init__.py`)",
+            "flat (`{name}/__init__.py`)",
+            "mixed Rust/Python (`python/{name}/__init__.py` + `Cargo.toml`)",
+        ];
+        let layout_default = layouts
+            .iter()
+            .position(|candidate| Some(*candidate) == layout)
+            .unwrap_or(0);
+        let layout_index = dialoguer::Select::new()
+            .with_prompt("Select a project layout")
+            .items(&layout_labels)
+            .default(layout_default)
+            .interact()?;
+        Some(layouts[layout_index])
+    };
+
+    let app_labels = ["Library", "Application"];
+    let app_index = dialoguer::Select::new()
+        .with_prompt("Is this an application or a library?")
+        .items(&app_labels)
+        .default(usize::from(app))
+        .interact()?;
+    let app = app_index == 1;
+
+    Ok(InteractiveAnswers {
+        build_backend,
+        layout,
+        app,
+    })
+}
+
+/// The name of a `clap::ValueEnum` variant as spelled on the command line (e.g. `flat`,
+/// `maturin`), rather than its Rust `Debug` form (e.g. `Flat`, `Maturin`).
+fn possible_value_name<T: ValueEnum>(value: T) -> String {
+    value
+        .to_possible_value()
+        .expect("all ProjectLayoutArg/ProjectBuildBackend variants have a possible value")
+        .get_name()
+        .to_string()
+}
+
+/// Validate the explicit `--flat`/`--layout`/`--build-backend` combination and resolve the
+/// "effective" layout (`--flat` implies `Flat` when no `--layout` was given).
+///
+/// A mixed layout and the `maturin` backend imply one another: `maturin` is the only backend
+/// that builds a Rust extension module, and a mixed layout is meaningless without one. Likewise,
+/// `--flat` pins the layout to `Flat`, so any other explicit `--layout` is a contradiction.
+/// Reject any explicit combination that asks for one without the other, rather than letting one
+/// flag silently win.
+fn validate_layout_backend(
+    build_backend: Option<ProjectBuildBackend>,
+    layout: Option<ProjectLayoutArg>,
+    flat: bool,
+) -> Result<Option<ProjectLayoutArg>> {
+    if flat {
+        if let Some(other) = layout.filter(|&layout| layout != ProjectLayoutArg::Flat) {
+            anyhow::bail!(
+                "`--flat` requires a flat layout, but `--layout {}` was requested",
+                possible_value_name(other)
+            );
+        }
+    }
+    let effective_layout = if flat { Some(ProjectLayoutArg::Flat) } else { layout };
+
+    match (build_backend, effective_layout) {
+        (Some(backend), Some(other @ (ProjectLayoutArg::Flat | ProjectLayoutArg::Src)))
+            if backend.is_maturin() =>
+        {
+            anyhow::bail!(
+                "`--build-backend maturin` requires a mixed layout, but `--layout {}` was requested",
+                possible_value_name(other)
+            );
+        }
+        (Some(backend), Some(ProjectLayoutArg::Mixed)) if !backend.is_maturin() => {
+            anyhow::bail!(
+                "A mixed layout requires `--build-backend maturin`, but `--build-backend {}` was requested",
+                possible_value_name(backend)
+            );
+        }
+        _ => {}
+    }
+
+    Ok(effective_layout)
+}
+
 /// Add one or more packages to the project requirements.
 #[allow(clippy::single_match_else)]
 pub(crate) async fn init(
@@ -26,6 +463,13 @@ pub(crate) async fn init(
     name: Option<PackageName>,
     no_readme: bool,
     no_pin: bool,
+    build_backend: Option<ProjectBuildBackend>,
+    layout: Option<ProjectLayoutArg>,
+    flat: bool,
+    template_dir: Option<PathBuf>,
+    app: bool,
+    interactive: bool,
+    no_interactive: bool,
     python: Option<String>,
     python_preference: PythonPreference,
     python_fetch: PythonFetch,
@@ -69,36 +513,86 @@ pub(crate) async fn init(
         anyhow::bail!("Package is already initialized")
     }
 
-    // Create the directory for the project.
-    let src_dir = project_dir.join("src").join(name.as_ref());
+    // Prompt for the backend/layout/app choices when running interactively, pre-filled with the
+    // values given on the command line (see `prompt_interactive` for the fallbacks).
+    let (build_backend, layout, app) = if should_prompt(
+        interactive,
+        no_interactive,
+        build_backend,
+        layout,
+        flat,
+    ) {
+        let answers = prompt_interactive(build_backend, layout, app, flat)?;
+        (answers.build_backend, answers.layout, answers.app)
+    } else {
+        (build_backend, layout, app)
+    };
+
+    // An explicit mixed layout implies the `maturin` backend when no backend was requested.
+    let effective_layout = validate_layout_backend(build_backend, layout, flat)?;
+    let build_backend = match (build_backend, effective_layout) {
+        (None, Some(ProjectLayoutArg::Mixed)) => Some(ProjectBuildBackend::Maturin),
+        (build_backend, _) => build_backend,
+    };
+
+    // `crate_name` is the sanitized, import-safe form of `name` (e.g. `my_project` for
+    // `my-project`), used for the package directory, the Rust crate, and the `[project.scripts]`
+    // entry point, all of which must agree with one another.
+    let crate_name = name.as_ref().replace('-', "_");
+
+    // Resolve the project layout from the explicit flags, the build backend, or whatever layout
+    // is already present on disk.
+    let project_layout =
+        ProjectLayout::resolve(layout, flat, build_backend, &project_dir, &crate_name);
+    let template_dir = template_dir.as_deref();
+
+    // Create the directory for the project. This is keyed on `crate_name`, not `name`, since the
+    // package directory also doubles as the Python import path (e.g. `src/my_project`, not
+    // `src/my-project`).
+    let src_dir = project_layout.package_dir(&project_dir, &crate_name);
+
+    let context = InitContext::new(name.clone(), crate_name, build_backend, project_layout, app);
     fs_err::create_dir_all(&src_dir)?;
 
     // Create the `pyproject.toml`.
     fs_err::write(
         project_dir.join("pyproject.toml"),
-        indoc::formatdoc! {r#"
-        [project]
-        name = "{name}"
-        version = "0.1.0"
-        description = "Add your description here"
-        dependencies = []
-        readme = "README.md"
-
-        [tool.uv]
-        dev-dependencies = []
-    "#},
+        render_template("pyproject.toml.jinja", template_dir, &context)?,
     )?;
 
-    // Create `src/{name}/__init__.py`.
+    // Key the Rust scaffold off the *resolved* layout, rather than `build_backend` alone, so it
+    // always agrees with where `init_py`/`main.py` are actually written.
+    if matches!(project_layout, ProjectLayout::Mixed) {
+        init_maturin_project(&project_dir, template_dir, &context)?;
+    }
+
+    // Create `{name}/__init__.py` in the resolved layout's package directory.
     let init_py = src_dir.join("__init__.py");
     // Avoid overwriting existing content.
     if !init_py.try_exists()? {
         fs_err::write(
             init_py,
-            indoc::formatdoc! {r#"
-            def hello() -> str:
-                return "Hello from {name}!"
-            "#},
+            render_template("__init__.py.jinja", template_dir, &context)?,
+        )?;
+    }
+
+    // Create `{name}/main.py`, the target of the `[project.scripts]` entry point.
+    if app {
+        let main_py = src_dir.join("main.py");
+        if !main_py.try_exists()? {
+            fs_err::write(
+                main_py,
+                render_template("main.py.jinja", template_dir, &context)?,
+            )?;
+        }
+    }
+
+    // Create the `.gitignore`.
+    let gitignore = project_dir.join(".gitignore");
+    if !gitignore.try_exists()? {
+        fs_err::write(
+            gitignore,
+            render_template(".gitignore.jinja", template_dir, &context)?,
         )?;
     }
 
@@ -172,3 +666,286 @@ pub(crate) async fn init(
 
     Ok(ExitStatus::Success)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A no-flag `uv init` should render a `pyproject.toml` identical to the one generated before
+    /// templating was introduced: no `[project.scripts]`, no `[build-system]`, no `[tool.maturin]`,
+    /// and no stray blank lines from the (unrendered) conditional blocks.
+    #[test]
+    fn pyproject_toml_no_flags() {
+        let context = InitContext::new(
+            PackageName::new("my-project".to_string()).unwrap(),
+            "my_project".to_string(),
+            None,
+            ProjectLayout::Src,
+            false,
+        );
+
+        let rendered = render_template("pyproject.toml.jinja", None, &context).unwrap();
+
+        assert_eq!(
+            rendered,
+            indoc::indoc! {r#"
+                [project]
+                name = "my-project"
+                version = "0.1.0"
+                description = "Add your description here"
+                dependencies = []
+                readme = "README.md"
+
+                [tool.uv]
+                dev-dependencies = []
+            "#}
+        );
+    }
+
+    /// For a hyphenated project name, the `[project.scripts]` entry point must target the same
+    /// sanitized `crate_name` that the package directory is created under, so the entry point is
+    /// actually resolvable.
+    #[test]
+    fn pyproject_toml_app_hyphenated_name() {
+        let name = PackageName::new("my-project".to_string()).unwrap();
+        let crate_name = "my_project".to_string();
+        let context = InitContext::new(name, crate_name.clone(), None, ProjectLayout::Src, true);
+
+        let rendered = render_template("pyproject.toml.jinja", None, &context).unwrap();
+
+        assert!(
+            rendered.contains("my-project = \"my_project.main:main\""),
+            "unexpected scripts entry:\n{rendered}"
+        );
+
+        let package_dir = ProjectLayout::Src.package_dir(Path::new("."), &crate_name);
+        assert_eq!(package_dir, Path::new("./src/my_project"));
+    }
+
+    /// With `--app` *and* `--build-backend maturin` together, `pyproject.toml.jinja` must render
+    /// all three conditional sections (`[project.scripts]`, `[build-system]`, `[tool.maturin]`)
+    /// in the order the template lists them, not just the single-flag combinations covered by
+    /// `pyproject_toml_no_flags`/`pyproject_toml_app_hyphenated_name`.
+    #[test]
+    fn pyproject_toml_app_maturin_mixed() {
+        let name = PackageName::new("my-project".to_string()).unwrap();
+        let context = InitContext::new(
+            name,
+            "my_project".to_string(),
+            Some(ProjectBuildBackend::Maturin),
+            ProjectLayout::Mixed,
+            true,
+        );
+
+        let rendered = render_template("pyproject.toml.jinja", None, &context).unwrap();
+
+        let scripts_pos = rendered
+            .find("[project.scripts]")
+            .expect("missing [project.scripts]");
+        let build_system_pos = rendered
+            .find("[build-system]")
+            .expect("missing [build-system]");
+        let maturin_pos = rendered
+            .find("[tool.maturin]")
+            .expect("missing [tool.maturin]");
+        assert!(
+            scripts_pos < build_system_pos && build_system_pos < maturin_pos,
+            "unexpected section ordering:\n{rendered}"
+        );
+
+        assert!(rendered.contains(r#"requires = ["maturin>=1.5,<2.0"]"#));
+        assert!(rendered.contains(r#"build-backend = "maturin""#));
+        assert!(rendered.contains(r#"python-source = "python""#));
+        assert!(rendered.contains(r#"module-name = "_core""#));
+        assert!(
+            !rendered.contains("\n\n\n"),
+            "stray blank lines from unrendered conditionals:\n{rendered}"
+        );
+    }
+
+    /// `main.py.jinja` (the `--app`/`--script` entry point) should render the project name into
+    /// its greeting and keep the `if __name__ == "__main__"` guard.
+    #[test]
+    fn main_py_renders_entry_point() {
+        let context = InitContext::new(
+            PackageName::new("my-project".to_string()).unwrap(),
+            "my_project".to_string(),
+            None,
+            ProjectLayout::Src,
+            true,
+        );
+
+        let rendered = render_template("main.py.jinja", None, &context).unwrap();
+
+        assert_eq!(
+            rendered,
+            indoc::indoc! {r#"
+                def main() -> None:
+                    print("Hello from my-project!")
+
+
+                if __name__ == "__main__":
+                    main()
+            "#}
+        );
+    }
+
+    /// `.gitignore.jinja` should cover the standard Python build/virtualenv artifacts.
+    #[test]
+    fn gitignore_renders_python_ignores() {
+        let context = InitContext::new(
+            PackageName::new("my-project".to_string()).unwrap(),
+            "my_project".to_string(),
+            None,
+            ProjectLayout::Src,
+            false,
+        );
+
+        let rendered = render_template(".gitignore.jinja", None, &context).unwrap();
+
+        assert_eq!(
+            rendered,
+            indoc::indoc! {r#"
+                # Python-generated files
+                __pycache__/
+                *.py[oc]
+                build/
+                dist/
+                wheels/
+                *.egg-info
+
+                # Virtual environments
+                .venv
+            "#}
+        );
+    }
+
+    /// A scratch directory for layout-detection tests, isolated by test name so parallel tests
+    /// don't see each other's files.
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("uv-init-test-{name}"));
+        let _ = fs_err::remove_dir_all(&dir);
+        fs_err::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Running `uv init` inside an existing Rust crate (identified by a `Cargo.toml`, with Rust
+    /// source under `src/`) should resolve to a mixed layout rather than mistaking the crate's
+    /// `src/` for an existing Python `src`-layout package.
+    #[test]
+    fn resolve_detects_existing_rust_crate() {
+        let dir = scratch_dir("rust-crate");
+        fs_err::write(dir.join("Cargo.toml"), "[package]\nname = \"demo\"\n").unwrap();
+        fs_err::create_dir_all(dir.join("src")).unwrap();
+        fs_err::write(dir.join("src").join("main.rs"), "fn main() {}\n").unwrap();
+
+        let layout = ProjectLayout::resolve(None, false, None, &dir, "demo");
+        assert_eq!(layout, ProjectLayout::Mixed);
+    }
+
+    /// An existing Python `src`-layout package (an `__init__.py` under `src/`) should still be
+    /// auto-detected as `Src`, unchanged from before the Rust-crate check was added.
+    #[test]
+    fn resolve_detects_existing_python_src_layout() {
+        let dir = scratch_dir("python-src");
+        fs_err::create_dir_all(dir.join("src").join("demo")).unwrap();
+        fs_err::write(dir.join("src").join("demo").join("__init__.py"), "").unwrap();
+
+        let layout = ProjectLayout::resolve(None, false, None, &dir, "demo");
+        assert_eq!(layout, ProjectLayout::Src);
+    }
+
+    /// `Mixed` always packages Python under `python/`, never back into the crate's own `src/`.
+    #[test]
+    fn mixed_package_dir_is_under_python() {
+        let package_dir = ProjectLayout::Mixed.package_dir(Path::new("."), "demo");
+        assert_eq!(package_dir, Path::new("./python/demo"));
+    }
+
+    /// Validation errors must echo the flag spelling users actually type (`flat`, `maturin`), not
+    /// Rust's `Debug` form (`Flat`, `Maturin`).
+    #[test]
+    fn validate_layout_backend_uses_clap_spelling() {
+        let err = validate_layout_backend(
+            Some(ProjectBuildBackend::Maturin),
+            Some(ProjectLayoutArg::Flat),
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`--build-backend maturin` requires a mixed layout, but `--layout flat` was requested"
+        );
+
+        let err = validate_layout_backend(
+            Some(ProjectBuildBackend::Hatchling),
+            Some(ProjectLayoutArg::Mixed),
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "A mixed layout requires `--build-backend maturin`, but `--build-backend hatchling` was requested"
+        );
+    }
+
+    /// `--flat` pins the layout to `Flat`; an explicit, contradictory `--layout` must be
+    /// rejected rather than silently overridden by `--flat`.
+    #[test]
+    fn validate_layout_backend_rejects_conflicting_flat_and_layout() {
+        let err = validate_layout_backend(None, Some(ProjectLayoutArg::Mixed), true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`--flat` requires a flat layout, but `--layout mixed` was requested"
+        );
+
+        // `--flat --layout flat` is redundant, not contradictory, and should be accepted.
+        let effective =
+            validate_layout_backend(None, Some(ProjectLayoutArg::Flat), true).unwrap();
+        assert_eq!(effective, Some(ProjectLayoutArg::Flat));
+    }
+
+    /// An explicit `--interactive` must still trigger a prompt when `--flat` is also passed: only
+    /// the layout question is meant to be skipped, not the whole interactive session.
+    #[test]
+    fn should_prompt_respects_explicit_interactive_with_flat() {
+        assert!(should_prompt(true, false, None, None, true));
+    }
+
+    /// `--no-interactive` always wins, even over an explicit `--interactive`.
+    #[test]
+    fn should_prompt_no_interactive_wins() {
+        assert!(!should_prompt(true, true, None, None, false));
+    }
+
+    /// When `--flat` has already pinned the layout, `maturin` (which requires a mixed layout)
+    /// must not be offered in the interactive backend prompt: picking it would only be rejected
+    /// later by `validate_layout_backend`, after the user has answered every other question.
+    #[test]
+    fn backend_choices_excludes_maturin_when_flat() {
+        let backends: Vec<_> = backend_choices(true)
+            .into_iter()
+            .map(|(backend, _)| backend)
+            .collect();
+        assert!(!backends.contains(&ProjectBuildBackend::Maturin));
+
+        let backends: Vec<_> = backend_choices(false)
+            .into_iter()
+            .map(|(backend, _)| backend)
+            .collect();
+        assert!(backends.contains(&ProjectBuildBackend::Maturin));
+    }
+
+    /// Even if `maturin` were somehow selected alongside `--flat` (e.g. a pre-filled default),
+    /// `validate_layout_backend` still rejects the resulting effective layout/backend pair,
+    /// exercising the exact `prompt_interactive` -> `validate_layout_backend` handoff.
+    #[test]
+    fn validate_layout_backend_rejects_maturin_with_flat() {
+        let err =
+            validate_layout_backend(Some(ProjectBuildBackend::Maturin), None, true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`--build-backend maturin` requires a mixed layout, but `--layout flat` was requested"
+        );
+    }
+}